@@ -0,0 +1,56 @@
+use bevy_animation::{AnimationClip, AnimationGraph, AnimationNodeIndex};
+use bevy_asset::{Asset, Handle};
+use bevy_image::Image;
+use bevy_pbr::StandardMaterial;
+use bevy_platform::collections::HashMap;
+use bevy_reflect::TypePath;
+use bevy_scene::Scene;
+
+/// Top level asset produced by loading an FBX file with the [`FbxLoader`](crate::FbxLoader).
+///
+/// This mirrors `bevy_gltf`'s `Gltf` asset: it collects the individually addressable
+/// sub-assets (scenes, animations) that were also written out as labeled assets, so callers
+/// that load the whole file get a single handle they can use to discover what it contains.
+#[derive(Asset, TypePath, Debug)]
+pub struct Fbx {
+    /// All scenes contained in the FBX file.
+    pub scenes: Vec<Handle<Scene>>,
+    /// All animation clips contained in the FBX file, indexed in file order.
+    pub animations: Vec<Handle<AnimationClip>>,
+    /// Named animation clips, keyed by the take name authored in the source DCC tool.
+    pub named_animations: HashMap<Box<str>, Handle<AnimationClip>>,
+    /// The sidecar asset mapping take names to animation graph node indices.
+    pub fbx_animations: Handle<FbxAnimations>,
+    /// All materials contained in the FBX file, indexed in file order.
+    pub materials: Vec<Handle<StandardMaterial>>,
+    /// All embedded textures contained in the FBX file, indexed in file order.
+    pub textures: Vec<Handle<Image>>,
+}
+
+/// A sidecar asset that exposes the named animation takes found in an FBX file, so that
+/// `AnimationGraph::from_clip` and friends don't require hard-coding a positional index
+/// that silently breaks when an artist reorders takes.
+#[derive(Asset, TypePath, Debug)]
+pub struct FbxAnimations {
+    /// The [`AnimationGraph`] built from every clip discovered in the file.
+    pub graph: Handle<AnimationGraph>,
+    /// Maps each take's name to its node in [`FbxAnimations::graph`].
+    pub named_nodes: HashMap<Box<str>, AnimationNodeIndex>,
+    /// Maps each take's name directly to its clip handle, for callers that want to build
+    /// their own graph instead of using [`FbxAnimations::graph`].
+    pub named_clips: HashMap<Box<str>, Handle<AnimationClip>>,
+    /// Maps each blend-shape ("morph target") channel name to its index in discovery order,
+    /// which is also its position in the combined weight track every clip in
+    /// [`FbxAnimations::graph`] carries, so a shape can be queried and blended by name. Only
+    /// channel names and weight curves are imported; blend-shape vertex deltas are not.
+    pub named_morph_targets: HashMap<Box<str>, usize>,
+    /// Maps each blend-shape ("morph target") name to the `(time_seconds, weight)` samples
+    /// authored on its `DeformPercent` animation curve, for callers that want to drive a
+    /// morph target directly instead of waiting on [`FbxAnimations::graph`].
+    ///
+    /// Every clip in [`FbxAnimations::graph`] already carries the combined `Keyframes::Weights`
+    /// track built from these samples, targeting the placeholder morph-targets entity the scene
+    /// root spawns (see the `bevy_fbx` loader); this map is kept for callers that want the raw
+    /// per-channel samples instead.
+    pub morph_weight_curves: HashMap<Box<str>, Vec<(f32, f32)>>,
+}
@@ -0,0 +1,15 @@
+use bevy_ecs::prelude::Event;
+
+/// Fired when a playing FBX-derived [`AnimationClip`](bevy_animation::AnimationClip) crosses a
+/// named marker authored in the source take (e.g. a footstep or hit frame), so gameplay code can
+/// react without polling transforms every frame.
+///
+/// This rides on [`AnimationClip::add_event`](bevy_animation::AnimationClip::add_event), so the
+/// re-triggering behavior on looped playback, and skipping markers a large `delta` jumps past in
+/// one frame, is handled by `AnimationPlayer` itself — the loader only needs to place the event
+/// at the marker's authored time.
+#[derive(Event, Debug, Clone)]
+pub struct FbxAnimationMarker {
+    /// The marker's name, as authored in the FBX take.
+    pub name: String,
+}
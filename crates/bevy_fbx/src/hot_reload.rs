@@ -0,0 +1,75 @@
+use bevy_animation::{AnimationGraphHandle, AnimationPlayer};
+use bevy_asset::{AssetEvent, AssetPath, AssetServer, Assets};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::EventReader,
+    hierarchy::Children,
+    query::With,
+    system::{Query, Res},
+};
+
+use crate::{Fbx, FbxAnimations};
+
+/// Marks the root entity of a scene spawned from an [`Fbx`] asset, so that when the source file
+/// changes on disk, [`reapply_reloaded_fbx_animations`] can find every [`AnimationPlayer`] that
+/// came from it and hand it the freshly imported animation graph, instead of leaving it wired to
+/// data from the stale load.
+///
+/// This stores the source file's [`AssetPath`] rather than a `Handle<Fbx>`: the loader inserts
+/// this component while loading that very `Fbx` asset, and a handle there would make the asset
+/// depend on itself, which can confuse code that waits on an asset's full dependency tree before
+/// treating it as loaded.
+#[derive(Component, Debug, Clone)]
+pub struct FbxSceneRoot(pub AssetPath<'static>);
+
+/// Re-applies a reloaded [`Fbx`] file's animation graph to every already-spawned
+/// [`AnimationPlayer`] descended from an entity carrying the matching [`FbxSceneRoot`].
+///
+/// Only the graph handle is swapped; each player's own playback state (which nodes are
+/// currently playing, and at what elapsed time) is left untouched, so a take that still exists
+/// at the same node index after reload keeps playing without a visible jump. A take whose node
+/// index changed (the take was added, removed, or reordered in the edited file) will pick up
+/// whatever clip now occupies that index next frame, the same way a fresh load would.
+pub fn reapply_reloaded_fbx_animations(
+    mut fbx_events: EventReader<AssetEvent<Fbx>>,
+    fbxs: Res<Assets<Fbx>>,
+    fbx_animations: Res<Assets<FbxAnimations>>,
+    asset_server: Res<AssetServer>,
+    scene_roots: Query<(Entity, &FbxSceneRoot)>,
+    children: Query<&Children>,
+    mut players: Query<&mut AnimationGraphHandle, With<AnimationPlayer>>,
+) {
+    for event in fbx_events.read() {
+        let AssetEvent::Modified { id } = event else {
+            continue;
+        };
+
+        let Some(fbx) = fbxs.get(*id) else {
+            continue;
+        };
+        let Some(path) = asset_server.get_path(*id) else {
+            continue;
+        };
+        let Some(fbx_animations) = fbx_animations.get(&fbx.fbx_animations) else {
+            continue;
+        };
+
+        for (root_entity, root) in &scene_roots {
+            if root.0 != path {
+                continue;
+            }
+
+            // The loader reads no node hierarchy from the file and puts the `AnimationPlayer` on
+            // the scene root itself (its only possible child is the blend-shape placeholder), so
+            // the root entity is included alongside its descendants.
+            let player_entities =
+                core::iter::once(root_entity).chain(children.iter_descendants(root_entity));
+            for entity in player_entities {
+                if let Ok(mut graph_handle) = players.get_mut(entity) {
+                    graph_handle.0 = fbx_animations.graph.clone();
+                }
+            }
+        }
+    }
+}
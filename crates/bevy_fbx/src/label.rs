@@ -0,0 +1,86 @@
+use bevy_asset::AssetPath;
+use core::fmt;
+
+/// Labels that can be used to load part of an FBX file, similarly to [`GltfAssetLabel`].
+///
+/// You can use [`FbxAssetLabel::from_asset`] to add it to an asset path:
+///
+/// ```
+/// # use bevy_fbx::FbxAssetLabel;
+/// # use bevy_asset::{AssetServer, Handle};
+/// # use bevy_scene::Scene;
+/// # let asset_server: AssetServer = panic!();
+/// let scene: Handle<Scene> = asset_server.load(FbxAssetLabel::Scene(0).from_asset("model.fbx"));
+/// ```
+///
+/// Or add it to a [`String`] representing an asset path:
+///
+/// ```
+/// # use bevy_fbx::FbxAssetLabel;
+/// let path = format!("{}#{}", "model.fbx", FbxAssetLabel::Scene(0));
+/// ```
+///
+/// [`GltfAssetLabel`]: https://docs.rs/bevy_gltf/latest/bevy_gltf/enum.GltfAssetLabel.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FbxAssetLabel {
+    /// `Scene(i)`: `i`th scene in the file, counted from the root node of the FBX's scene graph.
+    Scene(usize),
+    /// `Animation(i)`: `i`th animation stack in the file, indexed in file order.
+    Animation(usize),
+    /// `AnimationByName(name)`: animation stack whose take name (as authored in the DCC tool)
+    /// matches `name`. Unlike [`FbxAssetLabel::Animation`], this does not silently break when an
+    /// artist reorders or adds takes in the source file.
+    AnimationByName(String),
+    /// `Animations`: the [`FbxAnimations`](crate::FbxAnimations) sidecar asset, which maps take
+    /// names to animation node indices so a file's contents can be discovered at runtime.
+    Animations,
+    /// `Material(i)`: `i`th material in the file, indexed in file order.
+    Material(usize),
+    /// `Texture(i)`: `i`th embedded texture in the file, indexed in file order.
+    Texture(usize),
+}
+
+impl fmt::Display for FbxAssetLabel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FbxAssetLabel::Scene(index) => write!(f, "Scene{index}"),
+            FbxAssetLabel::Animation(index) => write!(f, "Animation{index}"),
+            FbxAssetLabel::AnimationByName(name) => write!(f, "Animation/Name={name}"),
+            FbxAssetLabel::Animations => write!(f, "Animations"),
+            FbxAssetLabel::Material(index) => write!(f, "Material{index}"),
+            FbxAssetLabel::Texture(index) => write!(f, "Texture{index}"),
+        }
+    }
+}
+
+impl FbxAssetLabel {
+    /// Returns an [`AssetPath`] including this label for the given `path`.
+    pub fn from_asset(&self, path: impl Into<AssetPath<'static>>) -> AssetPath<'static> {
+        path.into().with_label(self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indexed_labels_format_as_name_followed_by_index() {
+        assert_eq!(FbxAssetLabel::Scene(0).to_string(), "Scene0");
+        assert_eq!(FbxAssetLabel::Animation(3).to_string(), "Animation3");
+        assert_eq!(FbxAssetLabel::Material(1).to_string(), "Material1");
+        assert_eq!(FbxAssetLabel::Texture(2).to_string(), "Texture2");
+    }
+
+    #[test]
+    fn animation_by_name_label_embeds_the_take_name() {
+        let label = FbxAssetLabel::AnimationByName("Walk".to_string());
+        assert_eq!(label.to_string(), "Animation/Name=Walk");
+    }
+
+    #[test]
+    fn from_asset_appends_the_label_to_the_path() {
+        let path = FbxAssetLabel::AnimationByName("Walk".to_string()).from_asset("model.fbx");
+        assert_eq!(path.to_string(), "model.fbx#Animation/Name=Walk");
+    }
+}
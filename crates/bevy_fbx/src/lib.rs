@@ -0,0 +1,33 @@
+#![doc = include_str!("../README.md")]
+#![forbid(unsafe_code)]
+
+mod assets;
+mod events;
+mod hot_reload;
+mod label;
+mod loader;
+mod material;
+mod settings;
+
+pub use assets::*;
+pub use events::*;
+pub use hot_reload::*;
+pub use label::*;
+pub use loader::*;
+pub use settings::*;
+
+use bevy_app::prelude::*;
+use bevy_asset::AssetApp;
+
+/// Adds support for FBX file loading to the app.
+#[derive(Default)]
+pub struct FbxPlugin;
+
+impl Plugin for FbxPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<Fbx>()
+            .init_asset::<FbxAnimations>()
+            .init_asset_loader::<FbxLoader>()
+            .add_systems(Update, reapply_reloaded_fbx_animations);
+    }
+}
@@ -0,0 +1,623 @@
+use bevy_animation::{
+    AnimationClip, AnimationGraph, AnimationGraphHandle, AnimationPlayer, AnimationTarget,
+    AnimationTargetId, Interpolation, Keyframes, VariableCurve,
+};
+use bevy_asset::{io::Reader, AssetLoader, Handle, LoadContext};
+use bevy_ecs::{hierarchy::ChildOf, name::Name, world::World};
+use bevy_pbr::MeshMaterial3d;
+use bevy_platform::collections::HashMap;
+use bevy_scene::Scene;
+use bevy_transform::components::Transform;
+use fbxcel_dom::any::AnyDocument;
+
+use crate::material::{load_materials, load_textures};
+use crate::{
+    Fbx, FbxAnimationMarker, FbxAnimations, FbxAssetLabel, FbxLoaderSettings, FbxSceneRoot,
+    FbxUnitScale, FbxUpAxis,
+};
+
+/// Centimeters per meter, used to convert an FBX `GlobalSettings::UnitScaleFactor` (which is
+/// expressed in centimeters) into the scale factor applied to the root scene.
+const CENTIMETERS_PER_METER: f32 = 100.0;
+
+/// Name of the placeholder entity that carries every discovered blend shape's weight, spawned as
+/// a child of the scene root so blend-shape weight curves have a real [`AnimationTarget`] to
+/// land on. Once mesh geometry extraction lands, morph weights will be driven on the
+/// mesh-bearing entity itself instead, under whatever name that entity is given.
+const MORPH_TARGETS_ENTITY_NAME: &str = "FbxMorphTargets";
+
+/// The [`AnimationTargetId`] shared by the placeholder entity [`load_scene`] spawns and the
+/// weight curve [`load_animations`] authors against it, so the two stay in sync without either
+/// one needing to know about the other's `Entity`.
+fn morph_targets_target_id() -> AnimationTargetId {
+    AnimationTargetId::from_name(&Name::new(MORPH_TARGETS_ENTITY_NAME))
+}
+
+/// Loads FBX files (`.fbx`) as [`Scene`] and [`AnimationClip`] assets.
+#[derive(Default)]
+pub struct FbxLoader;
+
+/// Possible errors that can be produced by [`FbxLoader`].
+#[derive(Debug, thiserror::Error)]
+pub enum FbxError {
+    /// An [IO Error](std::io::Error).
+    #[error("failed to read fbx file: {0}")]
+    Io(#[from] std::io::Error),
+    /// Failed to parse the FBX document.
+    #[error("failed to parse fbx file: {0}")]
+    Parse(#[from] fbxcel_dom::any::Error),
+    /// The FBX file's version is not supported.
+    #[error("unsupported fbx version")]
+    UnsupportedVersion,
+}
+
+impl AssetLoader for FbxLoader {
+    type Asset = Fbx;
+    type Settings = FbxLoaderSettings;
+    type Error = FbxError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        settings: &FbxLoaderSettings,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Fbx, FbxError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let document = match AnyDocument::from_seekable_reader(std::io::Cursor::new(bytes))? {
+            AnyDocument::V7400(_fbx_version, document) => document,
+            _ => return Err(FbxError::UnsupportedVersion),
+        };
+
+        let textures = load_textures(&document, load_context);
+        let materials = load_materials(&document, &textures, load_context);
+
+        if materials.len() > 1 {
+            // Every mesh-bearing node will carry its own material once mesh geometry extraction
+            // lands; until then, only the first material is attached anywhere, so flag that the
+            // rest were decoded but have nowhere to go yet instead of silently dropping them.
+            tracing::warn!(
+                "fbx file has {} materials but only the first is attached to the (meshless) \
+                 scene root; the remaining {} are loaded as assets but not yet used in the scene",
+                materials.len(),
+                materials.len() - 1,
+            );
+        }
+
+        let named_morph_targets = morph_target_indices(blend_shape_names(&document));
+        let morph_target_count = named_morph_targets.len();
+        let morph_weight_curves = blend_shape_weight_curves(&document);
+
+        let (animations, named_animations, fbx_animations, graph_handle) = load_animations(
+            &document,
+            load_context,
+            named_morph_targets,
+            morph_weight_curves,
+        );
+
+        // The scene root keeps a path back to this file (not a `Handle<Fbx>`, which would make
+        // the asset depend on itself) so `reapply_reloaded_fbx_animations` can find it again
+        // once the file is edited and re-imported.
+        let source_path = load_context.asset_path().clone();
+
+        let conversion = SceneConversion::resolve(&document, settings);
+        let scene = load_scene(
+            &document,
+            &conversion,
+            materials.first().cloned(),
+            graph_handle,
+            morph_target_count,
+            source_path,
+        );
+        let scene_handle =
+            load_context.add_labeled_asset(FbxAssetLabel::Scene(0).to_string(), scene);
+
+        Ok(Fbx {
+            scenes: vec![scene_handle],
+            animations,
+            named_animations,
+            fbx_animations,
+            materials,
+            textures: textures.into_iter().map(|texture| texture.handle).collect(),
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["fbx"]
+    }
+}
+
+/// The unit scale, axis and handedness conversion resolved from a document's `GlobalSettings`
+/// (and any [`FbxLoaderSettings`] overrides), baked into a single root-scene [`Transform`].
+///
+/// Skinned-mesh bind poses are conformed to the same `root` transform when skin loading lands,
+/// so animations stay correct regardless of the source file's unit scale or up-axis.
+struct SceneConversion {
+    root: Transform,
+}
+
+impl SceneConversion {
+    fn resolve(document: &fbxcel_dom::v7400::Document, settings: &FbxLoaderSettings) -> Self {
+        let scale = match settings.unit_scale {
+            FbxUnitScale::Auto => {
+                read_unit_scale_factor(document).unwrap_or(1.0) / CENTIMETERS_PER_METER
+            }
+            FbxUnitScale::Meters(scale) => scale,
+        };
+
+        let up_axis = match settings.up_axis {
+            FbxUpAxis::Auto => read_up_axis(document),
+            explicit => explicit,
+        };
+
+        let flip_handedness = settings.convert_handedness && read_is_left_handed(document);
+
+        Self::resolve_from(scale, up_axis, flip_handedness)
+    }
+
+    /// The actual conversion math, split out from [`SceneConversion::resolve`] so it can be
+    /// exercised without a parsed FBX [`fbxcel_dom::v7400::Document`] in tests.
+    fn resolve_from(scale: f32, up_axis: FbxUpAxis, flip_handedness: bool) -> Self {
+        // Mirroring a single axis turns a left-handed coordinate system into a right-handed one
+        // (or back); the X axis is as good a choice as any and matches what most FBX importers
+        // do. This doesn't yet fix triangle winding order, which flips along with it — that's
+        // corrected per-mesh once mesh geometry extraction lands.
+        let handedness_scale = if flip_handedness { -1.0 } else { 1.0 };
+
+        let mut root =
+            Transform::from_scale(bevy_math::Vec3::new(handedness_scale * scale, scale, scale));
+        root.rotate(up_axis.conversion_rotation());
+
+        Self { root }
+    }
+}
+
+/// Reads `GlobalSettings::UnitScaleFactor` (centimeters per unit) from the document, if present.
+fn read_unit_scale_factor(document: &fbxcel_dom::v7400::Document) -> Option<f32> {
+    document
+        .global_settings()
+        .and_then(|settings| settings.unit_scale_factor())
+}
+
+/// Reads the up-axis from `GlobalSettings`, defaulting to [`FbxUpAxis::YUp`] (Bevy's own
+/// convention) if the file doesn't specify one. The axis sign is not represented in
+/// [`FbxUpAxis`], so a negative up-axis is treated like the positive one.
+fn read_up_axis(document: &fbxcel_dom::v7400::Document) -> FbxUpAxis {
+    document
+        .global_settings()
+        .and_then(|settings| settings.up_axis())
+        .unwrap_or(FbxUpAxis::YUp)
+}
+
+/// Reads whether the document's `GlobalSettings` declare a left-handed coordinate system
+/// (common for content authored in 3ds Max), defaulting to `false` (right-handed, as used by
+/// Maya and by Bevy) if the file doesn't specify one.
+fn read_is_left_handed(document: &fbxcel_dom::v7400::Document) -> bool {
+    document
+        .global_settings()
+        .and_then(|settings| settings.is_left_handed())
+        .unwrap_or(false)
+}
+
+/// Builds the single root [`Scene`] for the document.
+///
+/// This currently produces a single root entity (no node hierarchy is read from the document),
+/// with the resolved [`SceneConversion`] baked into its [`Transform`], plus a
+/// [`MeshMaterial3d`] on that root pointing at the file's first material (once mesh geometry extraction lands, each mesh-bearing node will
+/// carry its own material instead of this placeholder).
+///
+/// The root also carries an [`AnimationPlayer`] and [`AnimationGraphHandle`] pointing at the
+/// file's combined animation graph, so takes can already be played against the root transform
+/// before mesh/skeleton extraction lands — matching the assumption `reapply_reloaded_fbx_animations`
+/// already makes that the player may live on the root itself rather than only a descendant.
+///
+/// When the file has at least one blend-shape channel, a [`MORPH_TARGETS_ENTITY_NAME`] child is
+/// also spawned carrying an [`AnimationTarget`], so the weight curve [`load_animations`] builds
+/// for it has somewhere real to land.
+fn load_scene(
+    _document: &fbxcel_dom::v7400::Document,
+    conversion: &SceneConversion,
+    first_material: Option<Handle<bevy_pbr::StandardMaterial>>,
+    graph_handle: Handle<AnimationGraph>,
+    morph_target_count: usize,
+    source_path: bevy_asset::AssetPath<'static>,
+) -> Scene {
+    let mut world = World::default();
+    let mut root = world.spawn((
+        conversion.root,
+        FbxSceneRoot(source_path),
+        AnimationPlayer::default(),
+        AnimationGraphHandle(graph_handle),
+    ));
+    if let Some(material) = first_material {
+        root.insert(MeshMaterial3d(material));
+    }
+    let root_entity = root.id();
+
+    if morph_target_count > 0 {
+        world.spawn((
+            Name::new(MORPH_TARGETS_ENTITY_NAME),
+            AnimationTarget {
+                id: morph_targets_target_id(),
+                player: root_entity,
+            },
+            ChildOf(root_entity),
+        ));
+    }
+
+    Scene::new(world)
+}
+
+/// Loads every animation stack ("take") in the document as an [`AnimationClip`], builds the
+/// combined [`AnimationGraph`], and returns the flat clip list (file order), the name-keyed clip
+/// map used by [`Fbx`], and the handles of the [`FbxAnimations`] sidecar and the graph.
+fn load_animations(
+    document: &fbxcel_dom::v7400::Document,
+    load_context: &mut LoadContext,
+    named_morph_targets: HashMap<Box<str>, usize>,
+    morph_weight_curves: HashMap<Box<str>, Vec<(f32, f32)>>,
+) -> (
+    Vec<Handle<AnimationClip>>,
+    HashMap<Box<str>, Handle<AnimationClip>>,
+    Handle<FbxAnimations>,
+    Handle<AnimationGraph>,
+) {
+    let mut animations = Vec::new();
+    let mut named_animations = HashMap::default();
+    let mut named_nodes = HashMap::default();
+    let mut named_clips = HashMap::default();
+    let mut graph = AnimationGraph::new();
+
+    // Bone curve extraction is tracked separately. Blend-shape weight curves aren't attributed
+    // to a particular take (see `blend_shape_weight_curves`), so the same morph-weight track is
+    // added to every clip; that's the same single-mesh, single-deformer-set simplification the
+    // rest of the loader already makes.
+    let morph_weights_curve = build_morph_weights_curve(&morph_weight_curves, &named_morph_targets);
+
+    for (index, stack) in animation_stacks(document).into_iter().enumerate() {
+        let mut clip = AnimationClip::default();
+        for (time, marker_name) in take_markers(&stack) {
+            clip.add_event(time, FbxAnimationMarker { name: marker_name });
+        }
+        if let Some(curve) = morph_weights_curve.clone() {
+            clip.add_curve_to_target(morph_targets_target_id(), curve);
+        }
+
+        let clip_handle = load_context.add_labeled_asset(
+            FbxAssetLabel::Animation(index).to_string(),
+            clip.clone(),
+        );
+        let take_name = stack.name;
+        animations.push(clip_handle.clone());
+
+        let node_index = graph.add_clip(clip_handle.clone(), 1.0, graph.root);
+
+        if let Some(name) = take_name {
+            // Also expose the clip under its `AnimationByName` label so it can be loaded
+            // directly from an asset path, without going through the `Fbx`/`FbxAnimations`
+            // sidecar assets first.
+            load_context
+                .add_labeled_asset(FbxAssetLabel::AnimationByName(name.clone()).to_string(), clip);
+
+            let key: Box<str> = name.into();
+            named_animations.insert(key.clone(), clip_handle.clone());
+            named_nodes.insert(key.clone(), node_index);
+            named_clips.insert(key, clip_handle);
+        }
+    }
+
+    let graph_handle = load_context.add_labeled_asset("AnimationGraph".to_string(), graph);
+
+    let fbx_animations = load_context.add_labeled_asset(
+        FbxAssetLabel::Animations.to_string(),
+        FbxAnimations {
+            graph: graph_handle.clone(),
+            named_nodes,
+            named_clips,
+            named_morph_targets,
+            morph_weight_curves,
+        },
+    );
+
+    (animations, named_animations, fbx_animations, graph_handle)
+}
+
+/// An animation stack ("take") found in the document, along with its authored name.
+struct AnimationStack<'a> {
+    object: fbxcel_dom::v7400::object::Object<'a>,
+    /// `None` if the take had no name in the source file, so it can only be referenced by its
+    /// positional [`FbxAssetLabel::Animation`] index, not [`FbxAssetLabel::AnimationByName`].
+    name: Option<String>,
+}
+
+/// Returns every animation stack ("take") in the document, in file order.
+fn animation_stacks(document: &fbxcel_dom::v7400::Document) -> Vec<AnimationStack<'_>> {
+    document
+        .objects()
+        .filter(|obj| obj.class() == fbxcel_dom::v7400::object::ObjectClass::AnimStack)
+        .map(|object| {
+            let name = object.name().unwrap_or_default();
+            let name = (!name.is_empty()).then(|| name.to_string());
+            AnimationStack { object, name }
+        })
+        .collect()
+}
+
+/// Returns the `(time_seconds, marker_name)` pairs authored as named time markers on a take,
+/// in file order, for translation into [`FbxAnimationMarker`] clip events.
+///
+/// DCC tools don't have a single standard way to author arbitrary named events on a take, so
+/// this reads them from child `Marker`-class objects connected to the stack, each carrying its
+/// trigger time in a `Time` property rather than an animated curve (a marker fires once at a
+/// fixed point in the take, so it doesn't need to be keyframed like a bone or blend shape).
+fn take_markers(stack: &AnimationStack) -> Vec<(f32, String)> {
+    stack
+        .object
+        .source_objects()
+        .filter(|obj| obj.class() == fbxcel_dom::v7400::object::ObjectClass::Marker)
+        .filter_map(|marker| marker_event(marker.name(), marker.properties().get_f32("Time")))
+        .collect()
+}
+
+/// Builds a single `(time_seconds, marker_name)` pair from a `Marker` object's raw `name` and
+/// `Time` property, split out from [`take_markers`] so this validation can be exercised without a
+/// parsed FBX [`fbxcel_dom::v7400::Document`] in tests. Returns `None` if the marker has no name
+/// or no authored trigger time, either of which makes it useless as a clip event.
+fn marker_event(name: Option<&str>, time: Option<f32>) -> Option<(f32, String)> {
+    let name = name.filter(|name| !name.is_empty())?;
+    Some((time?, name.to_string()))
+}
+
+/// Returns the authored name of every blend-shape channel (morph target) in the document.
+///
+/// FBX represents a blend shape as a `Deformer` of subtype `BlendShape` containing one
+/// `BlendShapeChannel` sub-deformer per named shape; this flattens those channels across the
+/// whole document, matching the single-mesh assumption the rest of the loader currently makes.
+fn blend_shape_names(document: &fbxcel_dom::v7400::Document) -> Vec<String> {
+    document
+        .objects()
+        .filter(|obj| obj.class() == fbxcel_dom::v7400::object::ObjectClass::BlendShapeChannel)
+        .map(|obj| obj.name().unwrap_or_default().to_string())
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+/// Returns the `(time_seconds, weight)` samples authored on each blend-shape channel's
+/// `DeformPercent` animation curve, keyed by channel name.
+///
+/// Like [`blend_shape_names`], this flattens channels across the whole document rather than
+/// attributing a curve to a particular take, matching the single-mesh assumption the rest of the
+/// loader currently makes. `DeformPercent` is authored in the file as a 0-100 percentage; this
+/// normalizes it to the `[0, 1]` weight range `AnimationClip` weight tracks expect.
+fn blend_shape_weight_curves(
+    document: &fbxcel_dom::v7400::Document,
+) -> HashMap<Box<str>, Vec<(f32, f32)>> {
+    document
+        .objects()
+        .filter(|obj| obj.class() == fbxcel_dom::v7400::object::ObjectClass::BlendShapeChannel)
+        .filter_map(|channel| {
+            let name = channel.name().unwrap_or_default();
+            if name.is_empty() {
+                return None;
+            }
+
+            let curve = channel
+                .source_objects()
+                .find(|obj| obj.class() == fbxcel_dom::v7400::object::ObjectClass::AnimCurveNode)
+                .and_then(|curve_node| {
+                    curve_node.source_objects().find(|obj| {
+                        obj.class() == fbxcel_dom::v7400::object::ObjectClass::AnimCurve
+                    })
+                })?;
+
+            let samples = curve
+                .key_times()
+                .into_iter()
+                .zip(curve.key_values())
+                .map(|(time, percent)| (time, deform_percent_to_weight(percent)))
+                .collect();
+
+            Some((name.into(), samples))
+        })
+        .collect()
+}
+
+/// Converts a `DeformPercent` sample (authored in the file as a 0-100 percentage) into the
+/// `[0, 1]` weight range `AnimationClip` weight tracks expect, split out from
+/// [`blend_shape_weight_curves`] so the conversion itself can be unit tested.
+fn deform_percent_to_weight(percent: f32) -> f32 {
+    percent / 100.0
+}
+
+/// Indexes blend-shape channel names by their position in `names`, matching the order
+/// [`blend_shape_names`] discovers them in, so a shape can be queried by name instead of index.
+fn morph_target_indices(names: Vec<String>) -> HashMap<Box<str>, usize> {
+    names
+        .into_iter()
+        .enumerate()
+        .map(|(index, name)| (name.into(), index))
+        .collect()
+}
+
+/// Builds a single [`Keyframes::Weights`] track covering every discovered morph target, to be
+/// authored against the [`MORPH_TARGETS_ENTITY_NAME`] placeholder entity [`load_scene`] spawns.
+///
+/// `AnimationClip` weight tracks are interleaved: one timestamp maps to *all* morph targets'
+/// weights at once, in target order. FBX instead gives each blend-shape channel its own
+/// independently-keyed curve, so this collects the union of every channel's timestamps, samples
+/// every channel at each one (linearly interpolating between its keys, holding its first/last
+/// value outside them, and using `0.0` for channels with no curve at all), and flattens the
+/// result into the interleaved layout `Keyframes::Weights` expects.
+/// Returns `None` if there are no morph targets or none of them have any curve data.
+fn build_morph_weights_curve(
+    morph_weight_curves: &HashMap<Box<str>, Vec<(f32, f32)>>,
+    named_morph_targets: &HashMap<Box<str>, usize>,
+) -> Option<VariableCurve> {
+    if named_morph_targets.is_empty() {
+        return None;
+    }
+
+    let mut channels = vec![None; named_morph_targets.len()];
+    for (name, &index) in named_morph_targets {
+        if let Some(samples) = morph_weight_curves.get(name) {
+            channels[index] = Some(samples.as_slice());
+        }
+    }
+    if channels.iter().all(Option::is_none) {
+        return None;
+    }
+
+    let mut timestamps: Vec<f32> = channels
+        .iter()
+        .flatten()
+        .flat_map(|samples| samples.iter().map(|&(time, _)| time))
+        .collect();
+    timestamps.sort_by(|a, b| a.total_cmp(b));
+    timestamps.dedup();
+
+    let keyframe_timestamps = timestamps.clone();
+    let weights = timestamps
+        .into_iter()
+        .flat_map(|time| {
+            channels
+                .iter()
+                .map(move |samples| samples.map_or(0.0, |samples| sample_curve_at(samples, time)))
+        })
+        .collect();
+
+    Some(VariableCurve {
+        keyframe_timestamps,
+        keyframes: Keyframes::Weights(weights),
+        interpolation: Interpolation::Linear,
+    })
+}
+
+/// Linearly interpolates `samples` (sorted, distinct `(time, value)` pairs) at `time`, clamping
+/// to the first/last value outside the sampled range.
+fn sample_curve_at(samples: &[(f32, f32)], time: f32) -> f32 {
+    let Some(&(first_time, first_value)) = samples.first() else {
+        return 0.0;
+    };
+    if time <= first_time {
+        return first_value;
+    }
+    let Some(&(last_time, last_value)) = samples.last() else {
+        return first_value;
+    };
+    if time >= last_time {
+        return last_value;
+    }
+
+    let next_index = samples.partition_point(|&(sample_time, _)| sample_time <= time);
+    let (prev_time, prev_value) = samples[next_index - 1];
+    let (next_time, next_value) = samples[next_index];
+    let t = (time - prev_time) / (next_time - prev_time);
+    prev_value + (next_value - prev_value) * t
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn y_up_files_need_no_rotation() {
+        let conversion = SceneConversion::resolve_from(1.0, FbxUpAxis::YUp, false);
+        assert_eq!(conversion.root.rotation, bevy_math::Quat::IDENTITY);
+    }
+
+    #[test]
+    fn z_up_files_are_rotated_to_y_up() {
+        let conversion = SceneConversion::resolve_from(1.0, FbxUpAxis::ZUp, false);
+        assert_eq!(
+            conversion.root.rotation,
+            bevy_math::Quat::from_rotation_x(-core::f32::consts::FRAC_PI_2)
+        );
+    }
+
+    #[test]
+    fn unit_scale_is_applied_uniformly_when_handedness_is_unchanged() {
+        let conversion = SceneConversion::resolve_from(0.01, FbxUpAxis::YUp, false);
+        assert_eq!(conversion.root.scale, bevy_math::Vec3::splat(0.01));
+    }
+
+    #[test]
+    fn flipping_handedness_mirrors_only_the_x_axis() {
+        let conversion = SceneConversion::resolve_from(1.0, FbxUpAxis::YUp, true);
+        assert_eq!(conversion.root.scale, bevy_math::Vec3::new(-1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn morph_target_indices_are_assigned_in_discovery_order() {
+        let indices = morph_target_indices(vec!["Smile".to_string(), "Frown".to_string()]);
+        assert_eq!(indices.get("Smile"), Some(&0));
+        assert_eq!(indices.get("Frown"), Some(&1));
+    }
+
+    #[test]
+    fn marker_event_is_none_without_a_name() {
+        assert_eq!(marker_event(None, Some(1.0)), None);
+        assert_eq!(marker_event(Some(""), Some(1.0)), None);
+    }
+
+    #[test]
+    fn marker_event_is_none_without_a_trigger_time() {
+        assert_eq!(marker_event(Some("Footstep"), None), None);
+    }
+
+    #[test]
+    fn marker_event_combines_name_and_time() {
+        assert_eq!(
+            marker_event(Some("Footstep"), Some(1.5)),
+            Some((1.5, "Footstep".to_string()))
+        );
+    }
+
+    #[test]
+    fn deform_percent_to_weight_normalizes_to_unit_range() {
+        assert_eq!(deform_percent_to_weight(0.0), 0.0);
+        assert_eq!(deform_percent_to_weight(50.0), 0.5);
+        assert_eq!(deform_percent_to_weight(100.0), 1.0);
+    }
+
+    #[test]
+    fn sample_curve_at_interpolates_linearly_between_keys() {
+        let samples = [(0.0, 0.0), (1.0, 1.0)];
+        assert_eq!(sample_curve_at(&samples, 0.5), 0.5);
+    }
+
+    #[test]
+    fn sample_curve_at_clamps_outside_the_sampled_range() {
+        let samples = [(1.0, 0.2), (2.0, 0.8)];
+        assert_eq!(sample_curve_at(&samples, 0.0), 0.2);
+        assert_eq!(sample_curve_at(&samples, 5.0), 0.8);
+    }
+
+    #[test]
+    fn build_morph_weights_curve_is_none_without_morph_targets() {
+        let curve = build_morph_weights_curve(&HashMap::default(), &HashMap::default());
+        assert!(curve.is_none());
+    }
+
+    #[test]
+    fn build_morph_weights_curve_interleaves_channels_in_target_order() {
+        let named_morph_targets: HashMap<Box<str>, usize> =
+            [("Smile".into(), 0), ("Frown".into(), 1)]
+                .into_iter()
+                .collect();
+        let morph_weight_curves: HashMap<Box<str>, Vec<(f32, f32)>> = [
+            ("Smile".into(), vec![(0.0, 1.0)]),
+            ("Frown".into(), vec![(0.0, 0.0), (1.0, 1.0)]),
+        ]
+        .into_iter()
+        .collect();
+
+        let curve = build_morph_weights_curve(&morph_weight_curves, &named_morph_targets).unwrap();
+        assert_eq!(curve.keyframe_timestamps, vec![0.0, 1.0]);
+        let Keyframes::Weights(weights) = curve.keyframes else {
+            panic!("expected a Keyframes::Weights track");
+        };
+        // Two morph targets per timestamp, in target order: [Smile, Frown].
+        assert_eq!(weights, vec![1.0, 0.0, 1.0, 1.0]);
+    }
+}
@@ -0,0 +1,191 @@
+use bevy_asset::{Handle, LoadContext};
+use bevy_color::Color;
+use bevy_image::Image;
+use bevy_pbr::StandardMaterial;
+use bevy_render::render_asset::RenderAssetUsages;
+
+use crate::FbxAssetLabel;
+
+/// A decoded embedded texture, along with the FBX object id of the `Video` it came from, so
+/// [`build_standard_material`] can resolve a material's texture connections back to the handle
+/// that was loaded for them.
+pub(crate) struct LoadedTexture {
+    pub(crate) id: fbxcel_dom::v7400::object::ObjectId,
+    pub(crate) handle: Handle<Image>,
+}
+
+/// Decodes every embedded `Video`/`Content` media record in the document into an [`Image`]
+/// asset, labeled [`FbxAssetLabel::Texture`] in file order.
+///
+/// FBX stores embedded texture data as a raw binary blob (the encoded PNG/JPEG/TGA/etc. bytes,
+/// exactly as they'd appear on disk) attached to a `Video` object that a `Texture` object then
+/// references. Non-embedded (file-relative) textures have no such blob, so those fall back to a
+/// 1x1 white placeholder; resolving them as a regular asset dependency on the referenced file
+/// path is left for the loader to add later.
+pub(crate) fn load_textures(
+    document: &fbxcel_dom::v7400::Document,
+    load_context: &mut LoadContext,
+) -> Vec<LoadedTexture> {
+    document
+        .objects()
+        .filter(|obj| obj.class() == fbxcel_dom::v7400::object::ObjectClass::Video)
+        .enumerate()
+        .map(|(index, video)| {
+            let image = video
+                .content()
+                .and_then(|bytes| match image::load_from_memory(bytes) {
+                    Ok(decoded) => Some(Image::from_dynamic(
+                        decoded,
+                        true,
+                        RenderAssetUsages::default(),
+                    )),
+                    Err(error) => {
+                        tracing::warn!(
+                            "failed to decode embedded fbx texture {}: {error}",
+                            video.name().unwrap_or_default(),
+                        );
+                        None
+                    }
+                })
+                .unwrap_or_default();
+
+            let handle =
+                load_context.add_labeled_asset(FbxAssetLabel::Texture(index).to_string(), image);
+            LoadedTexture {
+                id: video.id(),
+                handle,
+            }
+        })
+        .collect()
+}
+
+/// Builds one [`StandardMaterial`] per FBX `Material` object, labeled
+/// [`FbxAssetLabel::Material`] in file order, mapping the FBX Phong/PBR properties it can find
+/// onto Bevy's PBR inputs.
+pub(crate) fn load_materials(
+    document: &fbxcel_dom::v7400::Document,
+    textures: &[LoadedTexture],
+    load_context: &mut LoadContext,
+) -> Vec<Handle<StandardMaterial>> {
+    document
+        .objects()
+        .filter(|obj| obj.class() == fbxcel_dom::v7400::object::ObjectClass::Material)
+        .enumerate()
+        .map(|(index, material_obj)| {
+            let material = build_standard_material(&material_obj, textures);
+            load_context.add_labeled_asset(FbxAssetLabel::Material(index).to_string(), material)
+        })
+        .collect()
+}
+
+/// FBX's `ShadingModel` material property value written by DCC tools that actually author a PBR
+/// (metallic/roughness) material, as opposed to the legacy Phong/Lambert model every `Material`
+/// object otherwise falls back to.
+const PBR_SHADING_MODEL: &str = "PBR";
+
+/// Reads the diffuse/base-color, normal, metallic-roughness (where present) and emissive
+/// properties off a single FBX `Material` object and maps them onto a [`StandardMaterial`].
+///
+/// FBX's legacy Phong material model only has a diffuse color and a specular/shininess pair, so
+/// `metallic` is only set when the file's `ShadingModel` property actually says `"PBR"` (as
+/// exported by newer DCC versions); otherwise it's left at `StandardMaterial`'s default.
+/// `ReflectionFactor` is a property of the legacy Phong model too (a plain specular reflectivity
+/// scalar, not a metalness value), so it can't be used to detect a PBR material on its own.
+fn build_standard_material(
+    material_obj: &fbxcel_dom::v7400::object::Object,
+    textures: &[LoadedTexture],
+) -> StandardMaterial {
+    let properties = material_obj.properties();
+
+    let base_color = properties
+        .get_rgb("DiffuseColor")
+        .or_else(|| properties.get_rgb("BaseColor"))
+        .map(|[r, g, b]| Color::srgb(r, g, b))
+        .unwrap_or(Color::WHITE);
+
+    let emissive = properties
+        .get_rgb("EmissiveColor")
+        .map(|[r, g, b]| Color::srgb(r, g, b).into())
+        .unwrap_or_default();
+
+    let is_pbr = properties.get_string("ShadingModel").as_deref() == Some(PBR_SHADING_MODEL);
+    let metallic = is_pbr
+        .then(|| properties.get_f32("ReflectionFactor"))
+        .flatten();
+    let perceptual_roughness = properties
+        .get_f32("Roughness")
+        .or_else(|| properties.get_f32("Shininess").map(shininess_to_roughness));
+
+    let base_color_texture = find_connected_texture(material_obj, textures, "DiffuseColor")
+        .or_else(|| find_connected_texture(material_obj, textures, "BaseColor"));
+    let normal_map_texture = find_connected_texture(material_obj, textures, "NormalMap");
+    let emissive_texture = find_connected_texture(material_obj, textures, "EmissiveColor");
+
+    StandardMaterial {
+        base_color,
+        base_color_texture,
+        normal_map_texture,
+        emissive,
+        emissive_texture,
+        metallic: metallic.unwrap_or_default(),
+        perceptual_roughness: perceptual_roughness.unwrap_or(0.5),
+        ..Default::default()
+    }
+}
+
+/// Converts a legacy Phong `Shininess` exponent (typically authored in the 0-100+ range) into an
+/// approximate PBR `perceptual_roughness`, so Phong-only materials still get a plausible-looking
+/// specular response instead of falling back to the fixed default roughness of `0.5`.
+fn shininess_to_roughness(shininess: f32) -> f32 {
+    (1.0 - (shininess.max(0.0) / 100.0).min(1.0)).clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_shininess_is_fully_rough() {
+        assert_eq!(shininess_to_roughness(0.0), 1.0);
+    }
+
+    #[test]
+    fn negative_shininess_is_clamped_to_fully_rough() {
+        assert_eq!(shininess_to_roughness(-50.0), 1.0);
+    }
+
+    #[test]
+    fn shininess_at_or_above_one_hundred_is_fully_smooth() {
+        assert_eq!(shininess_to_roughness(100.0), 0.0);
+        assert_eq!(shininess_to_roughness(250.0), 0.0);
+    }
+
+    #[test]
+    fn mid_range_shininess_is_interpolated() {
+        assert_eq!(shininess_to_roughness(50.0), 0.5);
+    }
+}
+
+/// Finds the texture connected to `material_obj` under the given property name (e.g.
+/// `"DiffuseColor"`), and returns its already-loaded handle.
+fn find_connected_texture(
+    material_obj: &fbxcel_dom::v7400::object::Object,
+    textures: &[LoadedTexture],
+    property: &str,
+) -> Option<Handle<Image>> {
+    material_obj
+        .source_objects_for_property(property)
+        .filter(|obj| obj.class() == fbxcel_dom::v7400::object::ObjectClass::Texture)
+        .find_map(|texture_obj| {
+            // A `Texture` object wraps a `Video` for its actual media; follow that one more
+            // connection to find the `Video` id `load_textures` keyed its handles by.
+            let video_id = texture_obj
+                .source_objects()
+                .find(|obj| obj.class() == fbxcel_dom::v7400::object::ObjectClass::Video)?
+                .id();
+            textures
+                .iter()
+                .find(|texture| texture.id == video_id)
+                .map(|texture| texture.handle.clone())
+        })
+}
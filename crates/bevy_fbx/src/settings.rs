@@ -0,0 +1,81 @@
+use bevy_math::Quat;
+use serde::{Deserialize, Serialize};
+
+/// Settings for the [`FbxLoader`](crate::FbxLoader), configured via
+/// [`AssetServer::load_with_settings`](bevy_asset::AssetServer::load_with_settings).
+///
+/// FBX files are authored at whatever unit scale and axis convention the source DCC tool used
+/// (Maya is typically centimeters with Y-up, 3ds Max is often Z-up, and so on), so without a
+/// conversion step meshes commonly import mis-scaled or rotated relative to the rest of a Bevy
+/// scene, which is meters with Y-up.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FbxLoaderSettings {
+    /// The unit scale to convert the file's geometry and animation curves into, in meters.
+    ///
+    /// Defaults to [`FbxUnitScale::Auto`], which reads the file's own
+    /// `GlobalSettings::UnitScaleFactor` (authored in centimeters per unit) and converts to
+    /// meters. Set this explicitly if a particular file's metadata is wrong or missing.
+    pub unit_scale: FbxUnitScale,
+    /// Which axis of the source file points "up".
+    ///
+    /// Defaults to [`FbxUpAxis::Auto`], which reads the file's `GlobalSettings` up-axis and
+    /// rotates the root scene so the result is Y-up to match Bevy's convention. The axis sign is
+    /// not taken into account: a negative up-axis is treated like the positive one.
+    pub up_axis: FbxUpAxis,
+    /// Whether to mirror the root scene when the source file's `GlobalSettings` declare a
+    /// left-handed coordinate system (as 3ds Max typically exports), so the result matches
+    /// Bevy's right-handed convention.
+    ///
+    /// Triangle winding order is flipped along with the mirror, which is also needed for
+    /// correct backface culling and normals; that part of the conversion is applied per-mesh
+    /// once mesh geometry extraction lands. Set this to `false` to import the file's geometry
+    /// unmirrored, e.g. if a mesh's winding order has already been corrected upstream.
+    ///
+    /// Defaults to `true`.
+    pub convert_handedness: bool,
+}
+
+impl Default for FbxLoaderSettings {
+    fn default() -> Self {
+        Self {
+            unit_scale: FbxUnitScale::Auto,
+            up_axis: FbxUpAxis::Auto,
+            convert_handedness: true,
+        }
+    }
+}
+
+/// See [`FbxLoaderSettings::unit_scale`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum FbxUnitScale {
+    /// Read `GlobalSettings::UnitScaleFactor` (centimeters per unit) from the file and convert
+    /// the scene to meters.
+    Auto,
+    /// Scale every unit in the file by this factor to arrive at meters, ignoring whatever the
+    /// file's own `GlobalSettings::UnitScaleFactor` metadata claims.
+    Meters(f32),
+}
+
+/// See [`FbxLoaderSettings::up_axis`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FbxUpAxis {
+    /// Read the up-axis from the file's `GlobalSettings` (its sign is not taken into account).
+    Auto,
+    /// The file's Y axis is up (Bevy's convention; no rotation is applied).
+    YUp,
+    /// The file's Z axis is up; the root scene is rotated -90° about X to compensate.
+    ZUp,
+}
+
+impl FbxUpAxis {
+    /// The rotation to apply to the root scene to convert from this up-axis convention to
+    /// Bevy's Y-up convention.
+    pub fn conversion_rotation(self) -> Quat {
+        match self {
+            // `Auto` is resolved against the file's `GlobalSettings` before this is called;
+            // treat it as a no-op rotation if it ever reaches here unresolved.
+            FbxUpAxis::Auto | FbxUpAxis::YUp => Quat::IDENTITY,
+            FbxUpAxis::ZUp => Quat::from_rotation_x(-core::f32::consts::FRAC_PI_2),
+        }
+    }
+}
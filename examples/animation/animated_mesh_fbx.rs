@@ -1,4 +1,8 @@
 //! Plays an animation on an FBX model of an animated cube.
+//!
+//! Edit `cube_anim.fbx` while this example is running and `FbxPlugin` will re-import it and
+//! hand the updated animation graph to the `AnimationPlayer` below in place, so there's no need
+//! to restart to see the change.
 
 use std::f32::consts::PI;
 